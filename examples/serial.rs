@@ -7,14 +7,13 @@
 //! Before running the example, execute serial-setup.sh in another shell.
 
 use std::io::{Error, ErrorKind, Read, Write};
-use std::thread;
 use std::time::Duration;
 
 use mio::event::Source;
 use mio::{Events, Interest, Poll, Token, Waker};
 use mio_serial::SerialPortBuilderExt;
 
-use thread_guard::ThreadGuard;
+use thread_guard::{CancellationToken, ThreadGuard};
 
 /// Echo port.
 const PORT_PATH_A: &str = "/tmp/ttyS20";
@@ -27,63 +26,61 @@ const SERIAL: Token = Token(0);
 const WAKE: Token = Token(1);
 
 /// Returns a thread guard for serial port echoing thread.
-fn port_a() -> Result<ThreadGuard<Result<(), Error>, Waker>, Error> {
+fn port_a() -> Result<ThreadGuard<Result<(), Error>>, Error> {
     let mut poll = Poll::new()?;
     let mut port = mio_serial::new(PORT_PATH_A, 0).open_native_async()?;
     let registry = poll.registry();
     port.register(registry, SERIAL, Interest::READABLE)?;
     let waker = Waker::new(registry, WAKE)?;
-    let guard = ThreadGuard::with_actions(
-        thread::spawn(move || {
-            let mut events = Events::with_capacity(256);
-            let mut buf = vec![0; 256];
-            'poll: loop {
-                // This call is blocking.
-                poll.poll(&mut events, None).unwrap();
-
-                for event in events.iter() {
-                    let token = event.token();
+
+    // The wake hook makes sure that, when the guard cancels the token to make
+    // the thread exit, the blocking `poll` call below is interrupted. The
+    // token keeps the `Waker` alive until the thread has actually exited, so
+    // there is no risk of the wake signal being sent to a dropped waker.
+    let token = CancellationToken::new().with_wake_hook(move || {
+        let _ = waker.wake();
+    });
+
+    let guard = ThreadGuard::spawn_cancellable_with(token, move |token| {
+        let mut events = Events::with_capacity(256);
+        let mut buf = vec![0; 256];
+        'poll: loop {
+            // This call is blocking.
+            poll.poll(&mut events, None).unwrap();
+
+            for event in events.iter() {
+                if event.token() == WAKE {
                     // We are asked to exit.
-                    if token == WAKE {
+                    if token.is_cancelled() {
                         break 'poll;
-                    } else {
-                        // There is something to read.
-                        loop {
-                            match port.read(&mut buf) {
-                                Ok(len) => {
-                                    println!(
-                                        "Port A received a message: {:?}, going to echo it.",
-                                        &buf[..len]
-                                    );
-                                    if port.write(&buf[..len])? != len {
-                                        return Err(Error::new(
-                                            ErrorKind::Other,
-                                            "Write did not fully succed.",
-                                        ));
-                                    };
-                                }
-                                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
-                                    break;
-                                }
-                                Err(e) => return Err(e),
+                    }
+                } else {
+                    // There is something to read.
+                    loop {
+                        match port.read(&mut buf) {
+                            Ok(len) => {
+                                println!(
+                                    "Port A received a message: {:?}, going to echo it.",
+                                    &buf[..len]
+                                );
+                                if port.write(&buf[..len])? != len {
+                                    return Err(Error::new(
+                                        ErrorKind::Other,
+                                        "Write did not fully succed.",
+                                    ));
+                                };
+                            }
+                            Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
+                                break;
                             }
+                            Err(e) => return Err(e),
                         }
                     }
                 }
             }
-            Ok(())
-        }),
-        waker,
-        |waker, _| {
-            // Make thread exit. That's important to make sure that the waker is
-            // alive until wake signal is delivered, that's why it is kept in
-            // the guard, not in the closure.
-            let _ = waker.wake();
-        },
-        |r| {
-            println!("Port A thread exited with the result {:?}.", r);
-        },
-    );
+        }
+        Ok(())
+    });
 
     Ok(guard)
 }
@@ -2,10 +2,114 @@
 #![warn(missing_docs, missing_debug_implementations, unreachable_pub)]
 #![forbid(unsafe_code)]
 
-use std::any::Any;
 use std::fmt;
+use std::future::Future;
 use std::mem;
-use std::thread::{JoinHandle, Result as ThreadResult};
+use std::panic;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle, Result as ThreadResult};
+use std::time::Duration;
+
+/// Shared state used to notify waiters that a spawned thread has completed,
+/// without requiring them to block on [`JoinHandle::join`] itself.
+///
+/// [`join_timeout`](ThreadGuard::join_timeout) waits on the condition
+/// variable, while [`join_async`](ThreadGuard::join_async) stores a waker to
+/// be woken once `done` is set.
+struct Completion {
+    state: Mutex<CompletionState>,
+    condvar: Condvar,
+}
+
+#[derive(Default)]
+struct CompletionState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+/// Policy applied to a guarded thread's panic payload when the guard is
+/// dropped without being explicitly joined.
+///
+/// The policy has no effect when the thread is joined through
+/// [`ThreadGuard::join`]: in that case the `ThreadResult` is simply handed
+/// back to the caller, who decides what to do with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Discard the panic payload (the default).
+    Ignore,
+    /// Re-raise the panic on the dropping thread via
+    /// [`std::panic::resume_unwind`].
+    ///
+    /// Suppressed if the guard is dropped while the dropping thread is
+    /// already unwinding from another panic, to avoid a double panic.
+    Resume,
+    /// Abort the process via [`std::process::abort`].
+    Abort,
+}
+
+/// A handle for cooperatively cancelling a guarded thread.
+///
+/// Cloning a `CancellationToken` is cheap; every clone refers to the same
+/// underlying flag. Worker code polls [`is_cancelled`](Self::is_cancelled) at
+/// its own loop boundaries and exits when it returns `true`. An optional wake
+/// hook can be attached with [`with_wake_hook`](Self::with_wake_hook) to
+/// interrupt code that would otherwise block past the next poll, e.g. a
+/// reactor's `poll`.
+#[derive(Clone)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    wake: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl CancellationToken {
+    /// Creates a new, non-cancelled token with no wake hook.
+    pub fn new() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            wake: None,
+        }
+    }
+
+    /// Attaches a hook that is run when the token is cancelled, e.g. to wake
+    /// a thread blocked in a reactor poll.
+    pub fn with_wake_hook<F>(mut self, wake: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.wake = Some(Arc::new(wake));
+        self
+    }
+
+    /// Returns `true` if the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Cancels the token and runs the wake hook, if one is set.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        if let Some(wake) = &self.wake {
+            wake();
+        }
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish_non_exhaustive()
+    }
+}
 
 /// A thread guard.
 ///
@@ -15,12 +119,19 @@ use std::thread::{JoinHandle, Result as ThreadResult};
 /// before and after thread joining, respectively. The thread can also be
 /// explicitly joined using the `join` method. In this case, the pre-action is
 /// executed before the join, and the thread result is returned to the caller.
-pub struct ThreadGuard<T>(
-    Option<(
+///
+/// By default, a panic in the guarded thread is silently discarded if the
+/// guard is dropped rather than explicitly joined; use
+/// [`on_panic`](Self::on_panic) to select a different [`PanicPolicy`].
+pub struct ThreadGuard<T> {
+    state: Option<(
         JoinHandle<T>,
         Box<dyn FnOnce(bool, JoinHandle<T>) -> ThreadResult<T> + Send>,
     )>,
-);
+    policy: PanicPolicy,
+    completion: Option<Arc<Completion>>,
+    cancel_token: Option<CancellationToken>,
+}
 
 impl<T> ThreadGuard<T> {
     /// Creates a new `ThreadGuard`.
@@ -28,7 +139,12 @@ impl<T> ThreadGuard<T> {
         let action =
             Box::new(move |_run_post_action, join_handle: JoinHandle<T>| join_handle.join());
 
-        Self(Some((handle, action)))
+        Self {
+            state: Some((handle, action)),
+            policy: PanicPolicy::Ignore,
+            completion: None,
+            cancel_token: None,
+        }
     }
 
     /// Creates a new `ThreadGuard` with the specified pre-action.
@@ -40,51 +156,298 @@ impl<T> ThreadGuard<T> {
     }
 
     /// Creates a new `ThreadGuard` with the specified post-action.
+    ///
+    /// Note: the post-action receives the thread's `&ThreadResult<T>` by
+    /// reference rather than by value (as it did before [`PanicPolicy`] was
+    /// introduced), because the same result is also inspected by `Drop` to
+    /// decide whether to apply the guard's panic policy. Clone or copy out
+    /// of it as needed.
     pub fn with_post_action<F>(handle: JoinHandle<T>, post_action: F) -> Self
     where
-        for<'a> F: FnOnce(ThreadResult<T>) + Send + 'a,
+        for<'a> F: FnOnce(&ThreadResult<T>) + Send + 'a,
     {
         Self::with_actions(handle, |_| {}, |_, result| post_action(result))
     }
 
     /// Creates a new `ThreadGuard` with the specified pre-action and
     /// post-action.
+    ///
+    /// Note: the post-action receives the thread's `&ThreadResult<T>` by
+    /// reference rather than by value (as it did before [`PanicPolicy`] was
+    /// introduced), because the same result is also inspected by `Drop` to
+    /// decide whether to apply the guard's panic policy. Clone or copy out
+    /// of it as needed.
     pub fn with_actions<U, F, G>(handle: JoinHandle<T>, pre_action: F, post_action: G) -> Self
     where
         for<'a> F: FnOnce(&JoinHandle<T>) -> U + Send + 'a,
-        for<'a> G: FnOnce(U, ThreadResult<T>) + Send + 'a,
+        for<'a> G: FnOnce(U, &ThreadResult<T>) + Send + 'a,
     {
         let action = Box::new(move |run_post_action, join_handle| {
             let arg = pre_action(&join_handle);
             let result = join_handle.join();
             if run_post_action {
-                post_action(arg, result);
-
-                // This is a bit ugly. Another possibility would be for `action`
-                // to return an `Option<ThreadResult<T>>` but then we will have
-                // yet another infallible `unwrap`.
-                return Err(Box::new(()) as Box<dyn Any + Send>);
+                post_action(arg, &result);
             }
 
             result
         });
 
-        Self(Some((handle, action)))
+        Self {
+            state: Some((handle, action)),
+            policy: PanicPolicy::Ignore,
+            completion: None,
+            cancel_token: None,
+        }
+    }
+
+    /// Spawns a new thread running `f` and returns a guard for it.
+    ///
+    /// Unlike [`ThreadGuard::new`], the returned guard tracks the thread's
+    /// completion through an internal notifier, which [`join_timeout`] relies
+    /// on to wait for the thread without blocking indefinitely.
+    ///
+    /// [`join_timeout`]: Self::join_timeout
+    pub fn spawn<F>(f: F) -> Self
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        Self::spawn_with_actions(f, |_| {}, |_, _| {})
+    }
+
+    /// Spawns a new thread running `f` with the specified pre-action and
+    /// post-action, and returns a guard for it.
+    ///
+    /// See [`ThreadGuard::spawn`] and [`ThreadGuard::with_actions`].
+    pub fn spawn_with_actions<F, U, G, H>(f: F, pre_action: G, post_action: H) -> Self
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+        for<'a> G: FnOnce(&JoinHandle<T>) -> U + Send + 'a,
+        for<'a> H: FnOnce(U, &ThreadResult<T>) + Send + 'a,
+    {
+        let completion = Arc::new(Completion {
+            state: Mutex::new(CompletionState::default()),
+            condvar: Condvar::new(),
+        });
+        let completion_clone = completion.clone();
+
+        let handle = thread::spawn(move || {
+            let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+
+            let mut state = completion_clone.state.lock().unwrap();
+            state.done = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+            drop(state);
+            completion_clone.condvar.notify_all();
+
+            match result {
+                Ok(value) => value,
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        });
+
+        let mut guard = Self::with_actions(handle, pre_action, post_action);
+        guard.completion = Some(completion);
+        guard
+    }
+
+    /// Spawns a new thread running `f` with a fresh [`CancellationToken`],
+    /// and returns a guard for it.
+    ///
+    /// The guard's pre-action cancels the token before joining: this both
+    /// flags the worker to exit at its next check of
+    /// [`is_cancelled`](CancellationToken::is_cancelled) and keeps the token
+    /// (and any wake hook attached to it) alive until the join completes,
+    /// fixing the lifetime footgun of threading a bare waker through the
+    /// guard by hand.
+    ///
+    /// This is also the mechanism that makes dropping an in-flight
+    /// [`join_async`](Self::join_async) future cheap: cancelling the token
+    /// (directly, or through [`cancel`](Self::cancel)) before dropping the
+    /// guard or future gives the worker a chance to exit promptly, instead
+    /// of the dropping thread blocking until it does. See the warning on
+    /// [`join_async`](Self::join_async) for details.
+    pub fn spawn_cancellable<F>(f: F) -> Self
+    where
+        F: FnOnce(CancellationToken) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        Self::spawn_cancellable_with(CancellationToken::new(), f)
+    }
+
+    /// Spawns a new thread running `f` with the given [`CancellationToken`],
+    /// and returns a guard for it.
+    ///
+    /// Use this instead of [`spawn_cancellable`](Self::spawn_cancellable)
+    /// when the token needs a wake hook attached before the thread starts,
+    /// e.g. to interrupt a blocking reactor poll (see
+    /// [`CancellationToken::with_wake_hook`]).
+    pub fn spawn_cancellable_with<F>(token: CancellationToken, f: F) -> Self
+    where
+        F: FnOnce(CancellationToken) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let worker_token = token.clone();
+        let pre_action_token = token.clone();
+
+        let mut guard = Self::spawn_with_actions(
+            move || f(worker_token),
+            move |_| pre_action_token.cancel(),
+            |_, _| {},
+        );
+        guard.cancel_token = Some(token);
+        guard
+    }
+
+    /// Cancels the guard's associated [`CancellationToken`], if any.
+    ///
+    /// Equivalent to calling [`CancellationToken::cancel`] on the token
+    /// handed to the worker closure, but does not require holding onto it
+    /// separately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the guard was not created through
+    /// [`spawn_cancellable`](Self::spawn_cancellable) or
+    /// [`spawn_cancellable_with`](Self::spawn_cancellable_with).
+    pub fn cancel(&self) {
+        self.cancel_token
+            .as_ref()
+            .expect("cancel requires a guard created with ThreadGuard::spawn_cancellable")
+            .cancel();
+    }
+
+    /// Sets the policy applied to a panic raised by the guarded thread when
+    /// the guard is dropped instead of explicitly joined.
+    pub fn on_panic(mut self, policy: PanicPolicy) -> Self {
+        self.policy = policy;
+        self
     }
 
     /// Joins the guarded thread.
     pub fn join(mut self) -> ThreadResult<T> {
-        let (handle, action) = self.0.take().unwrap();
-        mem::forget(self);
-
+        let (handle, action) = self.state.take().unwrap();
         action(false, handle)
     }
+
+    /// Waits up to `dur` for the guarded thread to complete, then joins it.
+    ///
+    /// If the thread completes in time, the pre-action is run and the thread
+    /// result is returned to the caller, consuming the guard exactly as
+    /// [`join`](Self::join) would. Otherwise, the still-live guard is handed
+    /// back to the caller: the underlying thread keeps running and will
+    /// still be joined when the guard is eventually joined or dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the guard was not created through [`ThreadGuard::spawn`] or
+    /// [`ThreadGuard::spawn_with_actions`], since only those track
+    /// completion.
+    pub fn join_timeout(self, dur: Duration) -> Result<ThreadResult<T>, Self> {
+        let completion = self
+            .completion
+            .clone()
+            .expect("join_timeout requires a guard created with ThreadGuard::spawn");
+
+        let state = completion.state.lock().unwrap();
+        let (state, _) = completion
+            .condvar
+            .wait_timeout_while(state, dur, |state| !state.done)
+            .unwrap();
+
+        if state.done {
+            drop(state);
+            Ok(self.join())
+        } else {
+            drop(state);
+            Err(self)
+        }
+    }
+
+    /// Returns a future that resolves to the guarded thread's result once it
+    /// completes.
+    ///
+    /// This lets a blocking worker thread be folded into async code without a
+    /// dedicated blocking-join thread: the future's `poll` checks the shared
+    /// completion state and, once set, runs the pre-action and joins the
+    /// thread (which returns immediately at that point); otherwise it
+    /// registers the waker and returns [`Poll::Pending`].
+    ///
+    /// # Warning
+    ///
+    /// If the future is dropped before the thread completes, the guard's
+    /// usual drop behavior applies: the dropping thread **blocks** until
+    /// `handle.join()` returns. This reintroduces the blocking join
+    /// `join_async` exists to avoid, and can stall or deadlock a
+    /// single-threaded executor if this future is dropped from a
+    /// cancellation-capable combinator (e.g. `tokio::select!`, a timeout, or
+    /// task cancellation). Only drop this future early if the guard was
+    /// created with [`ThreadGuard::spawn_cancellable`] (or
+    /// [`spawn_cancellable_with`](ThreadGuard::spawn_cancellable_with)) and
+    /// the worker is guaranteed to observe cancellation and exit promptly;
+    /// otherwise, poll it to completion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the guard was not created through [`ThreadGuard::spawn`] or
+    /// [`ThreadGuard::spawn_with_actions`], since only those track
+    /// completion.
+    pub fn join_async(self) -> impl Future<Output = ThreadResult<T>> {
+        self.completion
+            .as_ref()
+            .expect("join_async requires a guard created with ThreadGuard::spawn");
+        JoinAsync(self)
+    }
+}
+
+/// Future returned by [`ThreadGuard::join_async`].
+///
+/// Only obtainable through `join_async`, so that a [`ThreadGuard`] not
+/// created with completion tracking can never be `.await`ed directly and
+/// hit the bare `unwrap()` in `poll` instead of `join_async`'s documented,
+/// friendlier panic message.
+struct JoinAsync<T>(ThreadGuard<T>);
+
+impl<T> Future for JoinAsync<T> {
+    type Output = ThreadResult<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let guard = &mut self.get_mut().0;
+        let completion = guard.completion.clone().unwrap();
+
+        let mut state = completion.state.lock().unwrap();
+        if state.done {
+            drop(state);
+            let (handle, action) = guard.state.take().unwrap();
+            Poll::Ready(action(false, handle))
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
 }
 
 impl<T> Drop for ThreadGuard<T> {
     fn drop(&mut self) {
-        let (handle, action) = self.0.take().unwrap();
-        let _ = action(true, handle);
+        let Some((handle, action)) = self.state.take() else {
+            return;
+        };
+
+        if let Err(payload) = action(true, handle) {
+            match self.policy {
+                PanicPolicy::Ignore => {}
+                PanicPolicy::Resume => {
+                    // Don't double panic if we are already unwinding.
+                    if !std::thread::panicking() {
+                        panic::resume_unwind(payload);
+                    }
+                }
+                PanicPolicy::Abort => std::process::abort(),
+            }
+        }
     }
 }
 
@@ -93,3 +456,297 @@ impl<T> fmt::Debug for ThreadGuard<T> {
         f.debug_struct("ThreadGuard").finish_non_exhaustive()
     }
 }
+
+/// A group of [`ThreadGuard`]s, managed and joined together.
+///
+/// Pushing guards into a group and then joining (or dropping) the group
+/// ensures that every member thread is joined, even if one of them panics.
+pub struct ThreadGuardGroup<T> {
+    guards: Vec<ThreadGuard<T>>,
+}
+
+impl<T> ThreadGuardGroup<T> {
+    /// Creates a new, empty group.
+    pub fn new() -> Self {
+        Self { guards: Vec::new() }
+    }
+
+    /// Adds a guard to the group.
+    pub fn push(&mut self, guard: ThreadGuard<T>) {
+        self.guards.push(guard);
+    }
+
+    /// Spawns a new thread running `f`, and adds its guard to the group.
+    ///
+    /// See [`ThreadGuard::spawn`].
+    pub fn spawn<F>(&mut self, f: F)
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        self.push(ThreadGuard::spawn(f));
+    }
+
+    /// Cancels every member that was created with a [`CancellationToken`].
+    ///
+    /// Members without an associated token, i.e. not created through
+    /// [`ThreadGuard::spawn_cancellable`] or
+    /// [`ThreadGuard::spawn_cancellable_with`], are left untouched.
+    pub fn cancel_all(&self) {
+        for guard in &self.guards {
+            if guard.cancel_token.is_some() {
+                guard.cancel();
+            }
+        }
+    }
+
+    /// Joins every member in insertion order and returns their results.
+    ///
+    /// If any member has the [`Resume`](PanicPolicy::Resume) panic policy
+    /// and its thread panicked, the first such panic payload is re-raised
+    /// via [`std::panic::resume_unwind`] only after every member has been
+    /// joined, so no thread is leaked because of another one's panic. As
+    /// with [`ThreadGuard`]'s own `Drop`, the re-raise is suppressed if the
+    /// joining thread is already unwinding from another panic, to avoid a
+    /// double panic; in that case the payload is simply left in the
+    /// returned vector, as if its policy had been
+    /// [`Ignore`](PanicPolicy::Ignore).
+    pub fn join_all(mut self) -> Vec<ThreadResult<T>> {
+        Self::join_members(mem::take(&mut self.guards))
+    }
+
+    /// Joins every member, deferring panic propagation until all of them
+    /// have been joined. Shared by [`join_all`](Self::join_all) and `Drop`.
+    fn join_members(guards: Vec<ThreadGuard<T>>) -> Vec<ThreadResult<T>> {
+        let mut results = Vec::with_capacity(guards.len());
+        let mut first_resume_panic_index = None;
+
+        for guard in guards {
+            let policy = guard.policy;
+            let result = guard.join();
+
+            if let (PanicPolicy::Abort, Err(_)) = (policy, &result) {
+                std::process::abort();
+            }
+            if first_resume_panic_index.is_none() {
+                if let (PanicPolicy::Resume, Err(_)) = (policy, &result) {
+                    first_resume_panic_index = Some(results.len());
+                }
+            }
+
+            results.push(result);
+        }
+
+        // Don't double panic if we are already unwinding: leave the payload
+        // in `results`, same as the `Ignore` policy would.
+        if !std::thread::panicking() {
+            if let Some(index) = first_resume_panic_index {
+                if let Err(payload) = results.remove(index) {
+                    panic::resume_unwind(payload);
+                }
+            }
+        }
+
+        results
+    }
+}
+
+impl<T> Default for ThreadGuardGroup<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for ThreadGuardGroup<T> {
+    fn drop(&mut self) {
+        Self::join_members(mem::take(&mut self.guards));
+    }
+}
+
+impl<T> fmt::Debug for ThreadGuardGroup<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ThreadGuardGroup")
+            .field("len", &self.guards.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::mpsc;
+    use std::task::Wake;
+
+    /// Wakes the parked thread it was created on; just enough of an executor
+    /// to drive a [`JoinAsync`](super::JoinAsync) future to completion
+    /// without pulling in an async runtime dependency.
+    struct ThreadWaker(thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(value) => return value,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn drop_ignores_panic_by_default() {
+        let guard = ThreadGuard::spawn(|| panic!("boom"));
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| drop(guard)));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn drop_resumes_panic_with_resume_policy() {
+        let guard = ThreadGuard::spawn(|| panic!("boom")).on_panic(PanicPolicy::Resume);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| drop(guard)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn drop_aborts_with_abort_policy() {
+        // `std::process::abort` can't be exercised in-process without killing
+        // the test runner, so re-exec this binary with the test filtered down
+        // to just this one and check that the child process did not exit
+        // normally.
+        if std::env::var_os("THREAD_GUARD_TEST_ABORT_CHILD").is_some() {
+            let guard = ThreadGuard::spawn(|| panic!("boom")).on_panic(PanicPolicy::Abort);
+            drop(guard);
+            unreachable!("guard drop should have aborted the process");
+        }
+
+        let exe = std::env::current_exe().unwrap();
+        let status = std::process::Command::new(exe)
+            .args(["tests::drop_aborts_with_abort_policy", "--exact", "--nocapture"])
+            .env("THREAD_GUARD_TEST_ABORT_CHILD", "1")
+            .status()
+            .unwrap();
+        assert!(!status.success(), "child process should have aborted");
+    }
+
+    #[test]
+    fn join_timeout_returns_guard_when_not_done() {
+        let (tx, rx) = mpsc::channel::<()>();
+        let guard = ThreadGuard::spawn(move || rx.recv().unwrap());
+
+        let guard = match guard.join_timeout(Duration::from_millis(50)) {
+            Ok(_) => panic!("thread should not have completed yet"),
+            Err(guard) => guard,
+        };
+
+        tx.send(()).unwrap();
+        guard.join().unwrap();
+    }
+
+    #[test]
+    fn join_timeout_joins_when_done_in_time() {
+        let guard = ThreadGuard::spawn(|| 42);
+        thread::sleep(Duration::from_millis(20));
+
+        match guard.join_timeout(Duration::from_secs(5)) {
+            Ok(result) => assert_eq!(result.unwrap(), 42),
+            Err(_) => panic!("thread should have completed within the timeout"),
+        }
+    }
+
+    #[test]
+    fn join_async_resolves_to_thread_result() {
+        let guard = ThreadGuard::spawn(|| 7);
+        let result = block_on(guard.join_async());
+        assert_eq!(result.unwrap(), 7);
+    }
+
+    #[test]
+    fn join_async_dropped_while_pending_still_blocks_and_joins() {
+        let done = Arc::new(AtomicBool::new(false));
+        let done_clone = done.clone();
+        let (tx, rx) = mpsc::channel::<()>();
+        let guard = ThreadGuard::spawn(move || {
+            rx.recv().unwrap();
+            done_clone.store(true, Ordering::SeqCst);
+        });
+
+        let future = guard.join_async();
+        tx.send(()).unwrap();
+        drop(future);
+
+        assert!(done.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn group_join_all_propagates_first_resume_panic_after_joining_all_members() {
+        let joined = Arc::new(AtomicUsize::new(0));
+        let joined_clone = joined.clone();
+
+        let mut group = ThreadGuardGroup::new();
+        group.push(ThreadGuard::spawn(|| panic!("boom")).on_panic(PanicPolicy::Resume));
+        group.push(ThreadGuard::spawn(move || {
+            joined_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| group.join_all()));
+        assert!(result.is_err());
+        assert_eq!(joined.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn group_join_all_suppresses_resume_panic_while_already_unwinding() {
+        // If the suppression in `join_members` ever regresses, this would
+        // resume-unwind a panic while already unwinding from the outer one
+        // below, aborting the process instead of just failing this test. Run
+        // it in a subprocess, as with `drop_aborts_with_abort_policy`, so a
+        // regression here fails only this test rather than the whole binary.
+        if std::env::var_os("THREAD_GUARD_TEST_NESTED_PANIC_CHILD").is_some() {
+            struct PanicOnDrop(Option<ThreadGuardGroup<()>>);
+
+            impl Drop for PanicOnDrop {
+                fn drop(&mut self) {
+                    let results = self.0.take().unwrap().join_all();
+                    assert_eq!(results.len(), 2);
+                    assert!(results[0].is_err());
+                    assert!(results[1].is_ok());
+                }
+            }
+
+            let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                let mut group = ThreadGuardGroup::new();
+                group.push(ThreadGuard::spawn(|| panic!("boom")).on_panic(PanicPolicy::Resume));
+                group.push(ThreadGuard::spawn(|| ()));
+                let _guard = PanicOnDrop(Some(group));
+
+                panic!("outer panic");
+            }));
+            assert!(result.is_err());
+            return;
+        }
+
+        let exe = std::env::current_exe().unwrap();
+        let status = std::process::Command::new(exe)
+            .args([
+                "tests::group_join_all_suppresses_resume_panic_while_already_unwinding",
+                "--exact",
+                "--nocapture",
+            ])
+            .env("THREAD_GUARD_TEST_NESTED_PANIC_CHILD", "1")
+            .status()
+            .unwrap();
+        assert!(status.success(), "child process should not have aborted");
+    }
+}